@@ -1,10 +1,13 @@
 use crate::{Error, Result};
 
 pub mod mod_calls;
+pub mod search_calls;
 pub mod tag_calls;
 pub mod user_calls;
 pub mod version_calls;
 
+pub use search_calls::FacetBuilder;
+
 /// Verify that a given string `input` is base62 compliant
 pub(crate) fn check_id_slug(input: &str) -> Result<()> {
     // This regex checks if there is any character that isn't valid in base62 e.g. '/'
@@ -0,0 +1,180 @@
+use crate::{
+    structures::{
+        search::{Index, SearchHit, SearchResults},
+        Number,
+    },
+    url_ext::{UrlJoinAll, UrlWithQuery},
+    Ferinth, Result, API_BASE_URL,
+};
+use futures::Stream;
+
+/// A builder for the `facets` query parameter, which encodes AND-of-ORs
+/// filters.
+///
+/// Modrinth facets are a JSON array of OR-groups: every group matches if _any_
+/// of its facets matches, and the overall filter matches only if _every_ group
+/// matches. This builder makes that structure unrepresentable-if-malformed:
+/// [`and`](FacetBuilder::and) starts a new OR-group, and
+/// [`or`](FacetBuilder::or) extends the current one.
+///
+/// ```rust
+/// # use ferinth::FacetBuilder;
+/// // (categories:fabric OR categories:forge) AND (versions:1.20.1)
+/// let facets = FacetBuilder::new("categories:fabric")
+///     .or("categories:forge")
+///     .and("versions:1.20.1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FacetBuilder {
+    groups: Vec<Vec<String>>,
+}
+
+impl FacetBuilder {
+    /// Start a new facet filter with `facet` as the first element of the first
+    /// OR-group
+    pub fn new(facet: impl Into<String>) -> Self {
+        Self {
+            groups: vec![vec![facet.into()]],
+        }
+    }
+
+    /// Start a new OR-group, ANDed with the groups so far
+    #[must_use]
+    pub fn and(mut self, facet: impl Into<String>) -> Self {
+        self.groups.push(vec![facet.into()]);
+        self
+    }
+
+    /// Add `facet` to the current OR-group
+    #[must_use]
+    pub fn or(mut self, facet: impl Into<String>) -> Self {
+        self.groups
+            .last_mut()
+            .expect("a FacetBuilder always has at least one group")
+            .push(facet.into());
+        self
+    }
+
+    /// Encode the facets as the JSON array the API expects
+    fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(&self.groups)?)
+    }
+}
+
+/// The number of hits requested per page by [`Ferinth::search_projects_stream`]
+const STREAM_PAGE_SIZE: Number = 100;
+
+impl Ferinth {
+    /// Search for projects matching `query`, filtered by `facets` and sorted by
+    /// `index`, returning `limit` hits starting at `offset`.
+    ///
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> ferinth::Result<()> {
+    /// # let modrinth = ferinth::Ferinth::new("example", None, None, None)?;
+    /// use ferinth::{structures::search::Index, FacetBuilder};
+    /// let results = modrinth.search_projects(
+    ///     "sodium",
+    ///     &FacetBuilder::new("project_type:mod"),
+    ///     Index::Relevance,
+    ///     0,
+    ///     10,
+    /// ).await?;
+    /// assert!(results.total_hits > 0);
+    /// # Ok(()) }
+    /// ```
+    pub async fn search_projects(
+        &self,
+        query: &str,
+        facets: &FacetBuilder,
+        index: Index,
+        offset: Number,
+        limit: Number,
+    ) -> Result<SearchResults> {
+        self.get(
+            API_BASE_URL.join_all(vec!["search"]).with_query(&[
+                ("query", query.to_string()),
+                ("facets", facets.to_json()?),
+                ("index", index.to_string()),
+                ("offset", offset.to_string()),
+                ("limit", limit.to_string()),
+            ]),
+        )
+        .await
+    }
+
+    /// Lazily stream every [`SearchHit`] matching `query` and `facets`, sorted
+    /// by `index`.
+    ///
+    /// Successive pages are fetched on demand by bumping the offset until the
+    /// whole result set has been yielded, so callers can consume all matches
+    /// without handling pagination themselves.
+    ///
+    /// ```rust
+    /// # #[tokio::main]
+    /// # async fn main() -> ferinth::Result<()> {
+    /// # let modrinth = ferinth::Ferinth::new("example", None, None, None)?;
+    /// use ferinth::{structures::search::Index, FacetBuilder};
+    /// use futures::StreamExt;
+    /// let mut hits = modrinth.search_projects_stream(
+    ///     "sodium",
+    ///     &FacetBuilder::new("project_type:mod"),
+    ///     Index::Downloads,
+    /// );
+    /// while let Some(hit) = hits.next().await {
+    ///     let _hit = hit?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn search_projects_stream<'a>(
+        &'a self,
+        query: &'a str,
+        facets: &'a FacetBuilder,
+        index: Index,
+    ) -> impl Stream<Item = Result<SearchHit>> + 'a {
+        struct State {
+            offset: Number,
+            total: Number,
+            started: bool,
+            buffer: std::collections::VecDeque<SearchHit>,
+        }
+
+        let init = State {
+            offset: 0,
+            total: 0,
+            started: false,
+            buffer: std::collections::VecDeque::new(),
+        };
+
+        futures::stream::unfold(init, move |mut state| async move {
+            while state.buffer.is_empty() {
+                if state.started && state.offset >= state.total {
+                    return None;
+                }
+                match self
+                    .search_projects(query, facets, index, state.offset, STREAM_PAGE_SIZE)
+                    .await
+                {
+                    Ok(results) => {
+                        state.started = true;
+                        state.total = results.total_hits;
+                        state.offset += STREAM_PAGE_SIZE;
+                        if results.hits.is_empty() {
+                            return None;
+                        }
+                        state.buffer.extend(results.hits);
+                    }
+                    // Surface the error once, then end the stream on the next poll
+                    Err(err) => {
+                        state.started = true;
+                        state.offset = state.total;
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+
+            let hit = state.buffer.pop_front().expect("buffer is non-empty");
+            Some((Ok(hit), state))
+        })
+    }
+}
@@ -0,0 +1,131 @@
+//! An OAuth2 authorisation-code flow with PKCE for obtaining Modrinth tokens.
+//!
+//! Rather than requiring users to paste a pre-minted token into
+//! [`Ferinth::new`](crate::Ferinth::new), an interactive application can drive
+//! this flow: send the user to [`AuthFlow::auth_url`], receive the `code` (and
+//! `state`) on the configured redirect, then call [`AuthFlow::exchange_code`]
+//! to trade it for a ready-to-use authenticated [`Ferinth`].
+
+use crate::{Error, Ferinth, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+/// The URL the user is sent to in order to authorise the application
+const AUTHORIZE_URL: &str = "https://modrinth.com/auth/authorize";
+/// The endpoint a received `code` is exchanged at for an access token
+const TOKEN_URL: &str = "https://api.modrinth.com/_internal/oauth/token";
+
+/// The unreserved character set a `code_verifier` is drawn from, per RFC 7636
+const UNRESERVED: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+
+/// A PKCE authorisation-code flow in progress.
+///
+/// A fresh `code_verifier` and `state` are generated on construction and held
+/// for the lifetime of the flow, so that the same values drive both the
+/// authorisation URL and the later code exchange.
+#[derive(Debug, Clone)]
+pub struct AuthFlow {
+    program_name: String,
+    version: Option<String>,
+    contact: Option<String>,
+    client_id: String,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    state: String,
+    code_verifier: String,
+}
+
+impl AuthFlow {
+    /// Begin an authorisation flow for the application identified by
+    /// `client_id`, returning to `redirect_uri` and requesting `scopes`.
+    ///
+    /// `program_name`, `version`, and `contact` identify the resulting
+    /// authenticated [`Ferinth`], exactly as they do for
+    /// [`Ferinth::new`](crate::Ferinth::new).
+    pub fn new(
+        program_name: &str,
+        version: Option<&str>,
+        contact: Option<&str>,
+        client_id: &str,
+        redirect_uri: &str,
+        scopes: &[&str],
+    ) -> Self {
+        Self {
+            program_name: program_name.to_string(),
+            version: version.map(ToString::to_string),
+            contact: contact.map(ToString::to_string),
+            client_id: client_id.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
+            state: random_string(32),
+            code_verifier: random_string(64),
+        }
+    }
+
+    /// The URL to send the user to in order to authorise the application
+    pub fn auth_url(&self) -> Result<Url> {
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(self.code_verifier.as_bytes()));
+        Ok(Url::parse_with_params(
+            AUTHORIZE_URL,
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", self.redirect_uri.as_str()),
+                ("scope", &self.scopes.join(" ")),
+                ("state", self.state.as_str()),
+                ("code_challenge", &challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )?)
+    }
+
+    /// Exchange the `code` received on the redirect for an authenticated
+    /// [`Ferinth`].
+    ///
+    /// `returned_state` is the `state` parameter echoed back by the
+    /// authorisation server; it is checked against the one that was sent and
+    /// an [`Error::StateMismatch`] is returned if they differ.
+    pub async fn exchange_code(&self, returned_state: &str, code: &str) -> Result<Ferinth> {
+        if returned_state != self.state {
+            return Err(Error::StateMismatch);
+        }
+
+        #[derive(serde::Deserialize)]
+        struct TokenResponse {
+            access_token: String,
+            #[allow(dead_code)]
+            expires_in: u64,
+        }
+
+        let response: TokenResponse = reqwest::Client::new()
+            .post(TOKEN_URL)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &self.redirect_uri),
+                ("client_id", &self.client_id),
+                ("code_verifier", &self.code_verifier),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ferinth::new(
+            &self.program_name,
+            self.version.as_deref(),
+            self.contact.as_deref(),
+            Some(&response.access_token),
+        )
+    }
+}
+
+/// Generate a random string of `length` characters from the unreserved set
+fn random_string(length: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..length)
+        .map(|_| UNRESERVED[rng.gen_range(0..UNRESERVED.len())] as char)
+        .collect()
+}
@@ -0,0 +1,163 @@
+#![doc = include_str!("../README.md")]
+#![warn(missing_docs)]
+
+mod api_calls;
+pub mod auth_flow;
+mod request;
+pub mod structures;
+mod url_ext;
+
+pub use api_calls::*;
+
+use once_cell::sync::Lazy;
+use reqwest::header;
+use url::Url;
+
+/// The base URL for the Modrinth API
+pub(crate) static API_BASE_URL: Lazy<Url> =
+    Lazy::new(|| Url::parse("https://api.modrinth.com/v2/").unwrap());
+
+/// A convenience type alias for `Result`s with this crate's [`Error`]
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The errors that can occur while using this crate
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// The provided string was not base62 compliant
+    #[error("The provided string was not base62 compliant")]
+    NotBase62,
+    /// The provided string was not SHA1 compliant
+    #[error("The provided string was not SHA1 compliant")]
+    NotSHA1,
+    /// The rate limit was hit and the request could not be completed within the
+    /// configured number of retries
+    #[error("Rate limited; gave up after {retries} retries")]
+    RateLimited {
+        /// The number of retries that were attempted before giving up
+        retries: usize,
+    },
+    /// The `state` returned by the authorisation server did not match the one
+    /// that was sent, indicating a possible CSRF attack
+    #[error("The returned OAuth2 state did not match the one that was sent")]
+    StateMismatch,
+    /// The API responded with a non-2xx status and a structured error body.
+    ///
+    /// When the body is not the expected `{ error, description }` shape,
+    /// `error` holds the status' canonical reason and `description` the raw
+    /// body, so the `status` remains available either way.
+    #[error("API error {status}: {error} ({description})")]
+    Api {
+        /// The HTTP status code of the response
+        status: u16,
+        /// The short, machine-readable error name
+        error: String,
+        /// A human-readable description of what went wrong
+        description: String,
+    },
+    /// An error occurred while processing the HTTP request
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+    /// An invalid header value was constructed
+    #[error(transparent)]
+    InvalidHeaderValue(#[from] header::InvalidHeaderValue),
+    /// An error occurred while parsing a URL
+    #[error(transparent)]
+    URLParseError(#[from] url::ParseError),
+    /// An error occurred while (de)serialising JSON
+    #[error(transparent)]
+    JSONError(#[from] serde_json::Error),
+}
+
+/// Configuration for the client-side rate limiter.
+///
+/// The Modrinth API enforces a budget of around 300 requests per minute and
+/// advertises the current state through the `X-Ratelimit-Remaining` and
+/// `X-Ratelimit-Reset` response headers, plus a `Retry-After` header on `429`
+/// responses. When enabled, [`Ferinth`] uses these to self-throttle rather
+/// than hard-failing the caller.
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// The maximum number of times a `429`-ed request will be retried before
+    /// giving up with [`Error::RateLimited`]
+    pub max_retries: usize,
+    /// Whether to sleep until the advertised reset instant once the remaining
+    /// budget reaches zero, rather than firing the next request immediately
+    pub respect_reset: bool,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            respect_reset: true,
+        }
+    }
+}
+
+/// An instance of the API to invoke API calls on.
+///
+/// To initialise this container,
+/// ```rust
+/// # use ferinth::Ferinth;
+/// let modrinth = Ferinth::new("example-mod-manager", Some("1.0.0"), Some("discord-invite"), None)
+///     .expect("Failed to initialise the client");
+/// // Use the `modrinth` instance to call the API
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ferinth {
+    client: reqwest::Client,
+    rate_limit: Option<RateLimitConfig>,
+}
+
+impl Ferinth {
+    /// Create a new API instance.
+    ///
+    /// `program_name` is the name of the program using this crate, `version` is
+    /// its version, and `contact` is some way of contacting you (e.g. an email
+    /// address or a Discord handle). These are combined into a `User-Agent`
+    /// header as requested by the Modrinth API guidelines. `authorisation` is
+    /// an optional Modrinth token used for authenticated calls.
+    pub fn new(
+        program_name: &str,
+        version: Option<&str>,
+        contact: Option<&str>,
+        authorisation: Option<&str>,
+    ) -> Result<Self> {
+        let mut headers = header::HeaderMap::new();
+
+        let mut user_agent = program_name.to_string();
+        if let Some(version) = version {
+            user_agent.push('/');
+            user_agent.push_str(version);
+        }
+        if let Some(contact) = contact {
+            user_agent.push_str(&format!(" ({contact})"));
+        }
+        headers.insert(header::USER_AGENT, user_agent.parse()?);
+
+        if let Some(authorisation) = authorisation {
+            headers.insert(header::AUTHORIZATION, authorisation.parse()?);
+        }
+
+        Ok(Self {
+            client: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()?,
+            rate_limit: None,
+        })
+    }
+
+    /// Enable client-side rate limiting using the given `config`.
+    ///
+    /// ```rust
+    /// # use ferinth::{Ferinth, RateLimitConfig};
+    /// let modrinth = Ferinth::new("example", None, None, None)
+    ///     .unwrap()
+    ///     .with_rate_limit(RateLimitConfig::default());
+    /// ```
+    #[must_use]
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limit = Some(config);
+        self
+    }
+}
@@ -0,0 +1,169 @@
+//! The core request layer that all API calls funnel through.
+//!
+//! In addition to (de)serialisation, this is where client-side rate limiting
+//! lives: when a [`RateLimitConfig`](crate::RateLimitConfig) is configured, the
+//! `X-Ratelimit-Remaining`/`X-Ratelimit-Reset` headers are inspected after
+//! every response so that the next call can be delayed until the budget resets,
+//! and `429` responses are retried after honouring their `Retry-After` header.
+
+use crate::{Error, Ferinth, Result};
+use reqwest::{Response, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+use url::Url;
+
+impl Ferinth {
+    /// Send the request produced by `builder`, applying rate-limit handling.
+    ///
+    /// `builder` is a closure rather than a [`reqwest::RequestBuilder`] so that
+    /// the request can be rebuilt for each retry.
+    async fn execute(
+        &self,
+        builder: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<Response> {
+        let mut retries = 0;
+        loop {
+            let response = builder().send().await?;
+
+            // A `429` means we have already exceeded the budget; retry after the
+            // server-advertised cool-off, up to the configured maximum.
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                let config = match &self.rate_limit {
+                    Some(config) => config,
+                    None => return check_status(response).await,
+                };
+                if retries >= config.max_retries {
+                    return Err(Error::RateLimited { retries });
+                }
+                retries += 1;
+                sleep(retry_after(&response)).await;
+                continue;
+            }
+
+            // If this response exhausted the budget, wait out the window before
+            // returning so the caller's next call doesn't immediately `429`.
+            if let Some(config) = &self.rate_limit {
+                if config.respect_reset && remaining(&response) == Some(0) {
+                    if let Some(reset) = reset_after(&response) {
+                        sleep(reset).await;
+                    }
+                }
+            }
+
+            return check_status(response).await;
+        }
+    }
+
+    /// Perform a GET request to `url` and deserialise the response body
+    pub(crate) async fn get<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
+        Ok(self
+            .execute(|| self.client.get(url.clone()))
+            .await?
+            .json()
+            .await?)
+    }
+
+    /// Perform a POST request to `url` with the given `body` and `content_type`
+    pub(crate) async fn post(
+        &self,
+        url: Url,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<Response> {
+        self.execute(|| {
+            self.client
+                .post(url.clone())
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .body(body.clone())
+        })
+        .await
+    }
+
+    /// Perform a POST request to `url` with the given `body` serialised as JSON
+    pub(crate) async fn post_json<B: Serialize>(&self, url: Url, body: B) -> Result<()> {
+        let body = serde_json::to_vec(&body)?;
+        self.execute(|| {
+            self.client
+                .post(url.clone())
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.clone())
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Perform a DELETE request to `url`
+    pub(crate) async fn delete(&self, url: Url) -> Result<Response> {
+        self.execute(|| self.client.delete(url.clone())).await
+    }
+}
+
+/// Modrinth's structured error envelope, as returned on failed requests
+#[derive(serde::Deserialize)]
+struct ApiError {
+    error: String,
+    description: String,
+}
+
+/// Return `response` unchanged if it was successful, otherwise deserialise the
+/// structured error body into [`Error::Api`].
+///
+/// If the body isn't the expected `{ error, description }` shape, the status'
+/// canonical reason and the raw body are used instead, so the status code is
+/// always surfaced.
+async fn check_status(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let code = status.as_u16();
+    let body = response.text().await?;
+    Err(match serde_json::from_str::<ApiError>(&body) {
+        Ok(envelope) => Error::Api {
+            status: code,
+            error: envelope.error,
+            description: envelope.description,
+        },
+        Err(_) => Error::Api {
+            status: code,
+            error: status.canonical_reason().unwrap_or("unknown").to_string(),
+            description: body,
+        },
+    })
+}
+
+/// Read the value of a numeric header, if present and well-formed
+fn header_u64(response: &Response, name: &str) -> Option<u64> {
+    response
+        .headers()
+        .get(name)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// The remaining request budget advertised by `X-Ratelimit-Remaining`
+fn remaining(response: &Response) -> Option<u64> {
+    header_u64(response, "x-ratelimit-remaining")
+}
+
+/// How long until the budget resets, from `X-Ratelimit-Reset` (in seconds)
+fn reset_after(response: &Response) -> Option<Duration> {
+    header_u64(response, "x-ratelimit-reset").map(Duration::from_secs)
+}
+
+/// How long to wait before retrying a `429`, from `Retry-After` (in seconds)
+fn retry_after(response: &Response) -> Duration {
+    header_u64(response, "retry-after")
+        .map(Duration::from_secs)
+        .unwrap_or_else(|| Duration::from_secs(1))
+}
+
+/// Sleep for `duration`, unless it is zero
+async fn sleep(duration: Duration) {
+    if !duration.is_zero() {
+        tokio::time::sleep(duration).await;
+    }
+}
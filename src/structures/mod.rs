@@ -0,0 +1,10 @@
+//! Models for (de)serialising the Modrinth API's requests and responses
+
+pub mod project;
+pub mod search;
+pub mod tag;
+pub mod user;
+pub mod version;
+
+/// An integer used for counts and pagination throughout the API
+pub type Number = usize;
@@ -0,0 +1,102 @@
+//! Models for the `/search` endpoint
+
+use super::Number;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// A page of search results
+#[derive(Deserialize, Debug, Clone)]
+pub struct SearchResults {
+    /// The projects that matched the query
+    pub hits: Vec<SearchHit>,
+    /// The offset this page started at
+    pub offset: Number,
+    /// The number of hits requested for this page
+    pub limit: Number,
+    /// The total number of hits the query matched across all pages
+    pub total_hits: Number,
+}
+
+/// A single project returned by a search
+#[derive(Deserialize, Debug, Clone)]
+pub struct SearchHit {
+    /// The project's ID
+    pub project_id: String,
+    /// The project's slug
+    pub slug: String,
+    /// The project type (e.g. `mod`, `modpack`)
+    pub project_type: String,
+    /// The username of the project's author
+    pub author: String,
+    /// The project's title
+    pub title: String,
+    /// A short description of the project
+    pub description: String,
+    /// The categories the project belongs to
+    pub categories: Vec<String>,
+    /// The categories displayed for the project, a subset of `categories`
+    pub display_categories: Vec<String>,
+    /// The Minecraft versions the project supports
+    pub versions: Vec<String>,
+    /// The total number of downloads the project has
+    pub downloads: Number,
+    /// The total number of users following the project
+    pub follows: Number,
+    /// A link to the project's icon, if it has one
+    pub icon_url: Option<String>,
+    /// When the project was first created
+    pub date_created: DateTime<Utc>,
+    /// When the project was last modified
+    pub date_modified: DateTime<Utc>,
+    /// The latest Minecraft version the project supports
+    pub latest_version: Option<String>,
+    /// The project's license ID
+    pub license: String,
+    /// The project's client-side support level
+    pub client_side: String,
+    /// The project's server-side support level
+    pub server_side: String,
+    /// Links to the project's gallery images
+    #[serde(default)]
+    pub gallery: Vec<String>,
+}
+
+/// The field search results are sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Index {
+    /// Sort by search relevance
+    Relevance,
+    /// Sort by total downloads
+    Downloads,
+    /// Sort by total follows
+    Follows,
+    /// Sort by creation date, newest first
+    Newest,
+    /// Sort by last-updated date, most recent first
+    Updated,
+}
+
+impl Index {
+    /// The value this index is encoded as in the query string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Index::Relevance => "relevance",
+            Index::Downloads => "downloads",
+            Index::Follows => "follows",
+            Index::Newest => "newest",
+            Index::Updated => "updated",
+        }
+    }
+}
+
+impl std::fmt::Display for Index {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Index::Relevance
+    }
+}